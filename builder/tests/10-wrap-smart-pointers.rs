@@ -0,0 +1,121 @@
+// Fields whose type is a smart pointer or a Cow force the caller to wrap every
+// value by hand before passing it to the builder:
+//
+//     builder.current_dir(Box::new(PathBuf::from("..")));
+//
+// Look for a field attribute #[builder(wrap)]. On a field of type Box<T>,
+// Rc<T> or Arc<T>, the setter should accept the inner T and do the wrapping
+// itself. On a field of type Cow<'a, str>, the setter should accept a &'a str
+// and store it as Cow::Borrowed.
+//
+//     impl<'a> CommandBuilder<'a> {
+//         fn current_dir(&mut self, current_dir: PathBuf) -> &mut Self {
+//             self.current_dir = ::std::option::Option::Some(
+//                 ::std::boxed::Box::new(current_dir),
+//             );
+//             self
+//         }
+//
+//         ...
+//     }
+//
+// Notice that Command has a lifetime parameter now. The builder struct will
+// need to carry the same generics as the input, which is a good opportunity to
+// get familiar with syn::Generics::split_for_impl.
+//
+// As with the Option check from test case 06, the macro only gets to look at
+// tokens. Recognize each type by its bare name or by its full path through
+// std or alloc, with or without a leading `::`:
+//
+//   - Box, std::boxed::Box, alloc::boxed::Box
+//   - Rc, std::rc::Rc, alloc::rc::Rc
+//   - Arc, std::sync::Arc, alloc::sync::Arc
+//   - Cow, std::borrow::Cow, alloc::borrow::Cow
+//
+// A path that only ends in one of these names, like my::Box<T>, names some
+// other type. Matching the last segment alone is not enough; test case 15
+// applies the same rule to Option. The inner type of the setter is the single
+// type argument in the last segment's angle brackets.
+//
+// Test case 09 already showed why the expanded code must not rely on the names
+// in the caller's prelude. That matters even more here because the expansion
+// now constructs these types instead of only mentioning them, so `Box::new`
+// would silently pick up whatever `Box` means at the call site. Refer to every
+// constructor through an absolute path such as ::std::boxed::Box::new or
+// ::std::borrow::Cow::Borrowed. A crate that is itself no_std would reach the
+// same items through ::alloc instead, but ::std is all this test case needs.
+//
+//
+// Resources:
+//
+//   - Splitting generics into the pieces needed by an impl block:
+//     https://docs.rs/syn/1.0/syn/struct.Generics.html#method.split_for_impl
+
+use derive_builder::Builder;
+use std::borrow::Cow;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[derive(Builder)]
+pub struct Command<'a> {
+    #[builder(wrap)]
+    executable: Cow<'a, str>,
+    #[builder(wrap)]
+    args: Rc<Vec<String>>,
+    #[builder(wrap)]
+    env: Arc<Vec<String>>,
+    #[builder(wrap)]
+    current_dir: Box<PathBuf>,
+}
+
+mod redefined {
+    use derive_builder::Builder;
+    use std::path::PathBuf;
+
+    type Box = ();
+    type Rc = ();
+    type Arc = ();
+    type Cow = ();
+
+    #[derive(Builder)]
+    pub struct Command<'a> {
+        #[builder(wrap)]
+        executable: std::borrow::Cow<'a, str>,
+        #[builder(wrap)]
+        args: std::rc::Rc<Vec<String>>,
+        #[builder(wrap)]
+        env: std::sync::Arc<Vec<String>>,
+        #[builder(wrap)]
+        current_dir: std::boxed::Box<PathBuf>,
+    }
+
+    pub fn check() {
+        let command = Command::builder()
+            .executable("cargo")
+            .args(vec![])
+            .env(vec![])
+            .current_dir(PathBuf::from(".."))
+            .build()
+            .unwrap();
+
+        assert_eq!(command.executable, "cargo");
+    }
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo")
+        .args(vec!["build".to_owned(), "--release".to_owned()])
+        .env(vec![])
+        .current_dir(PathBuf::from(".."))
+        .build()
+        .unwrap();
+
+    assert!(matches!(command.executable, Cow::Borrowed("cargo")));
+    assert_eq!(*command.args, ["build", "--release"]);
+    assert!(command.env.is_empty());
+    assert_eq!(*command.current_dir, PathBuf::from(".."));
+
+    redefined::check();
+}
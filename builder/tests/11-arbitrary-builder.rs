@@ -0,0 +1,103 @@
+// Property tests and fuzzers want a steady supply of builders that are filled
+// in with random but complete data. Look for a container attribute
+// #[builder(arbitrary)] and, if present, generate:
+//
+//     impl Command {
+//         pub fn arbitrary_builder(seed: u64) -> CommandBuilder {
+//             ...
+//         }
+//     }
+//
+// Values for a field come from the function named in the field attribute
+// #[builder(arbitrary = "...")]. The function receives one random u64 and
+// turns it into a value of the field's type, or of the element type for a
+// field that also has #[builder(each = "...")]. Fields without a generator
+// fall back to std::default::Default::default().
+//
+//   - A required field is always set.
+//   - An Option field is set or left empty with equal probability.
+//   - An `each` field gets between 0 and 7 elements, pushed one at a time
+//     through the one-at-a-time setter so that it exercises the same code path
+//     as hand-written callers.
+//
+// The builder returned by arbitrary_builder must always be complete, meaning
+// build() succeeds on it for every seed.
+//
+// Respecting validation hooks is out of scope for this test case. The builder
+// has no validation hooks yet, so "complete" only means that every required
+// field is set. If a later test case adds a way for build() to reject values,
+// arbitrary_builder will have to respect it too.
+//
+// The randomness has to be deterministic so that a failing seed can be
+// replayed, and it has to come from code that the macro emits itself: a
+// proc-macro crate can only export macros, and pulling in an RNG crate just to
+// shuffle a few integers is not worth the extra dependency. A splitmix64
+// generator is a few lines and plenty good for this purpose.
+//
+//     let mut state = seed;
+//     let mut next = || {
+//         state = state.wrapping_add(0x9e3779b97f4a7c15);
+//         let mut z = state;
+//         z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+//         z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+//         z ^ (z >> 31)
+//     };
+//
+// Keep in mind test case 09: everything in the expansion, including the
+// fallback to Default, goes through absolute paths.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(arbitrary)]
+pub struct Command {
+    #[builder(arbitrary = "executable")]
+    executable: String,
+    #[builder(each = "arg", arbitrary = "word")]
+    args: Vec<String>,
+    #[builder(each = "env")]
+    env: Vec<String>,
+    #[builder(arbitrary = "word")]
+    current_dir: Option<String>,
+}
+
+fn executable(random: u64) -> String {
+    ["cargo", "rustc", "rustdoc"][random as usize % 3].to_owned()
+}
+
+fn word(random: u64) -> String {
+    format!("w{}", random % 1000)
+}
+
+fn main() {
+    let mut saw_none = false;
+    let mut saw_some = false;
+    let mut arg_counts = Vec::new();
+
+    for seed in 0..256 {
+        let command = Command::arbitrary_builder(seed).build().unwrap();
+        assert!(["cargo", "rustc", "rustdoc"].contains(&command.executable.as_str()));
+        assert!(command.args.len() < 8);
+        assert!(command.args.iter().all(|arg| arg.starts_with('w')));
+        assert!(command.env.iter().all(String::is_empty));
+
+        match &command.current_dir {
+            Some(dir) => {
+                assert!(dir.starts_with('w'));
+                saw_some = true;
+            }
+            None => saw_none = true,
+        }
+        arg_counts.push(command.args.len());
+
+        let again = Command::arbitrary_builder(seed).build().unwrap();
+        assert_eq!(command.executable, again.executable);
+        assert_eq!(command.args, again.args);
+        assert_eq!(command.env, again.env);
+        assert_eq!(command.current_dir, again.current_dir);
+    }
+
+    assert!(saw_none && saw_some);
+    assert!(arg_counts.contains(&0));
+    assert!(arg_counts.iter().any(|&n| n > 1));
+}
@@ -0,0 +1,135 @@
+// Builder-backed structs often describe configuration that is written by
+// people who never look at the Rust code. Look for a container attribute
+// #[builder(schema = "...")] and generate two functions that describe the
+// struct:
+//
+//     impl Command {
+//         pub fn schema() -> schema::BuilderSchema {...}
+//         pub fn json_schema() -> &'static str {...}
+//     }
+//
+// Crates that have the "proc-macro" crate type are only allowed to export
+// procedural macros, so derive_builder cannot provide the BuilderSchema type
+// itself. Instead the attribute names the module in which the caller has
+// defined BuilderSchema, FieldSchema and Kind, and the expansion refers to
+// them through that path. Every field of these types is a const-constructible
+// value that owns no data, such as a &'static str, an Option of one, a
+// &'static slice or the Kind enum, so the whole description can be built in a
+// constant expression.
+//
+// For each field record:
+//
+//   - its name;
+//   - its type as written in the source, printed the way rustdoc would show it
+//     (`Vec<String>`, not the `Vec < String >` that TokenStream::to_string
+//     produces);
+//   - whether it is required, an Option, or repeated through
+//     #[builder(each = "...")];
+//   - the value it takes when never set, as JSON text: `null` for an Option
+//     and `[]` for a repeated field;
+//   - its doc comment. Doc comments reach the macro as #[doc = "..."]
+//     attributes, one per line. Strip the single leading space that `///`
+//     leaves behind and join the lines with a newline.
+//
+// json_schema() returns the same information as JSON Schema text. All of it
+// is known at expansion time, so render the JSON inside the macro and emit it
+// as a single string literal. Map String to "string", Option<T> to T or null,
+// and a Vec<T> to an array of T. Types the macro does not recognize get no
+// "type" keyword at all, which JSON Schema treats as "anything".
+
+use derive_builder::Builder;
+
+pub mod schema {
+    #[derive(Debug, PartialEq)]
+    pub struct BuilderSchema {
+        pub name: &'static str,
+        pub doc: Option<&'static str>,
+        pub fields: &'static [FieldSchema],
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct FieldSchema {
+        pub name: &'static str,
+        pub ty: &'static str,
+        pub kind: Kind,
+        pub default: Option<&'static str>,
+        pub doc: Option<&'static str>,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub enum Kind {
+        Required,
+        Optional,
+        Repeated { each: &'static str },
+    }
+}
+
+/// How to launch a child process.
+#[derive(Builder)]
+#[builder(schema = "schema")]
+pub struct Command {
+    /// Program to run.
+    executable: String,
+    /// Arguments passed to the program.
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    env: Vec<String>,
+    /// Working directory, defaults to
+    /// the current one.
+    current_dir: Option<String>,
+}
+
+fn main() {
+    use schema::{BuilderSchema, FieldSchema, Kind};
+
+    let expected = BuilderSchema {
+        name: "Command",
+        doc: Some("How to launch a child process."),
+        fields: &[
+            FieldSchema {
+                name: "executable",
+                ty: "String",
+                kind: Kind::Required,
+                default: None,
+                doc: Some("Program to run."),
+            },
+            FieldSchema {
+                name: "args",
+                ty: "Vec<String>",
+                kind: Kind::Repeated { each: "arg" },
+                default: Some("[]"),
+                doc: Some("Arguments passed to the program."),
+            },
+            FieldSchema {
+                name: "env",
+                ty: "Vec<String>",
+                kind: Kind::Required,
+                default: None,
+                doc: None,
+            },
+            FieldSchema {
+                name: "current_dir",
+                ty: "Option<String>",
+                kind: Kind::Optional,
+                default: Some("null"),
+                doc: Some("Working directory, defaults to\nthe current one."),
+            },
+        ],
+    };
+    assert_eq!(Command::schema(), expected);
+
+    let expected = concat!(
+        r#"{"$schema":"https://json-schema.org/draft/2020-12/schema","#,
+        r#""title":"Command","#,
+        r#""description":"How to launch a child process.","#,
+        r#""type":"object","#,
+        r#""properties":{"#,
+        r#""executable":{"description":"Program to run.","type":"string"},"#,
+        r#""args":{"description":"Arguments passed to the program.","type":"array","items":{"type":"string"},"default":[]},"#,
+        r#""env":{"type":"array","items":{"type":"string"}},"#,
+        r#""current_dir":{"description":"Working directory, defaults to\nthe current one.","type":["string","null"],"default":null}"#,
+        r#"},"#,
+        r#""required":["executable","env"]}"#,
+    );
+    assert_eq!(Command::json_schema(), expected);
+}
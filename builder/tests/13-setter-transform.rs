@@ -0,0 +1,94 @@
+// Sometimes the most convenient argument for a setter is not the field's own
+// type. A working directory is stored as a String in Command, but most callers
+// have a &Path in hand.
+//
+// Look for field attributes of the form:
+//
+//     #[builder(setter(name = "cwd", transform = |p: &Path| p.display().to_string()))]
+//
+// Each setter(...) generates one additional setter method. Its argument type
+// is the type annotation on the closure's single parameter, and the closure's
+// output becomes the field value:
+//
+//     impl CommandBuilder {
+//         fn cwd(&mut self, p: &Path) -> &mut Self {
+//             let transform = |p: &Path| p.display().to_string();
+//             self.current_dir = std::option::Option::Some(transform(p));
+//             self
+//         }
+//     }
+//
+// For an Option field the closure produces the inner type, the same type that
+// the plain setter from test case 06 accepts. A field may have any number of
+// setter(...) entries, and the plain setter named after the field is still
+// generated alongside them, so the same field can be set from a &Path or from
+// a String. If `name` is left out, the setter takes the field's own name and
+// replaces the plain setter.
+//
+// A closure does not fit the key = "literal" shape that syn::Meta handles,
+// so the attribute will need to be parsed by hand. Parse the value after
+// `transform =` as a syn::ExprClosure, and reject closures whose parameter has
+// no type annotation since there would be nothing to put in the setter's
+// signature.
+//
+//
+// Resources:
+//
+//   - Parsing the tokens inside an attribute with a custom parser:
+//     https://docs.rs/syn/1.0/syn/struct.Attribute.html#method.parse_args_with
+//
+//   - The syntax tree for a closure expression:
+//     https://docs.rs/syn/1.0/syn/struct.ExprClosure.html
+
+use derive_builder::Builder;
+use std::path::Path;
+
+#[derive(Builder)]
+pub struct Command {
+    #[builder(setter(name = "exe", transform = |name: &str| name.to_owned()))]
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(setter(transform = |vars: &[(&str, &str)]| {
+        vars.iter().map(|(k, v)| format!("{}={}", k, v)).collect()
+    }))]
+    env: Vec<String>,
+    #[builder(
+        setter(name = "cwd", transform = |p: &Path| p.display().to_string()),
+        setter(name = "cwd_parent", transform = |p: &Path| p.join("..").display().to_string()),
+    )]
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .exe("cargo")
+        .arg("build".to_owned())
+        .env(&[("RUST_LOG", "debug")])
+        .cwd(Path::new("/tmp"))
+        .build()
+        .unwrap();
+
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.env, vec!["RUST_LOG=debug"]);
+    assert_eq!(command.current_dir.as_deref(), Some("/tmp"));
+
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .env(&[])
+        .current_dir("..".to_owned())
+        .build()
+        .unwrap();
+
+    assert!(command.args.is_empty());
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .env(&[])
+        .cwd_parent(Path::new("/tmp"))
+        .build()
+        .unwrap();
+
+    assert_eq!(command.current_dir.as_deref(), Some("/tmp/.."));
+}
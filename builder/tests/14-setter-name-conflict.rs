@@ -0,0 +1,24 @@
+// Extra setters from the previous test case share one namespace with the
+// plain setters and with the one-at-a-time setters from #[builder(each =
+// "...")]. Two methods with the same name in the generated impl would be a
+// compile error anyway, but rustc would point at the derive rather than at the
+// attribute that caused the clash.
+//
+// Detect setter names that are used twice across the whole struct and report
+// the error on the name that was given second. The one exception from test case
+// 07 still applies: an `each` name equal to its own field's name is allowed,
+// and suppresses the plain setter instead of clashing with it.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(setter(name = "arg", transform = |var: &str| vec![var.to_owned()]))]
+    env: Vec<String>,
+    current_dir: Option<String>,
+}
+
+fn main() {}
@@ -0,0 +1,100 @@
+// Test case 06 made the setter of an Option<T> field accept a plain T. That
+// leaves no way to put a field back to None once something has been set, for
+// example when a helper function fills in defaults that a caller wants to
+// undo.
+//
+// For every Option field, generate a second setter with an `_opt` suffix that
+// takes the whole Option:
+//
+//     impl CommandBuilder {
+//         fn current_dir_opt(&mut self, current_dir: Option<String>) -> &mut Self {
+//             self.current_dir = current_dir;
+//             self
+//         }
+//     }
+//
+// Also look for a field attribute #[builder(strip_option = false)]. On such a
+// field the regular setter takes the full Option type and no `_opt` setter is
+// generated, since it would be identical.
+//
+// Only the outermost Option is treated as "this field is optional". For a field
+// of type Option<Option<T>>, the regular setter takes Option<T>, and the
+// `_opt` setter takes Option<Option<T>>.
+//
+// Test case 06 only asked to recognize the literal tokens Option<...>. Now also
+// recognize the fully qualified spellings std::option::Option<...>,
+// ::std::option::Option<...> and core::option::Option<...>. At the same time,
+// a path that merely ends in a segment called Option, like config::Option<T>
+// below, names some other type and must be treated as an ordinary required
+// field. Checking the last path segment alone is not enough.
+
+use derive_builder::Builder;
+
+mod config {
+    #[derive(Debug, PartialEq)]
+    pub struct Option<T>(pub T);
+}
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    args: Vec<String>,
+    env: Vec<String>,
+    current_dir: Option<String>,
+    timeout: std::option::Option<Option<u64>>,
+    #[builder(strip_option = false)]
+    stdin: ::std::option::Option<String>,
+    priority: config::Option<u8>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .args(vec![])
+        .env(vec![])
+        .current_dir("..".to_owned())
+        .timeout(None)
+        .stdin(Some("input".to_owned()))
+        .priority(config::Option(1))
+        .build()
+        .unwrap();
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+    assert_eq!(command.timeout, Some(None));
+    assert_eq!(command.stdin.as_deref(), Some("input"));
+    assert_eq!(command.priority, config::Option(1));
+
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .args(vec![])
+        .env(vec![])
+        .current_dir("..".to_owned())
+        .current_dir_opt(None)
+        .timeout_opt(Some(Some(30)))
+        .priority(config::Option(1))
+        .build()
+        .unwrap();
+    assert!(command.current_dir.is_none());
+    assert_eq!(command.timeout, Some(Some(30)));
+
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .args(vec![])
+        .env(vec![])
+        .timeout(Some(30))
+        .timeout_opt(None)
+        .stdin(Some("input".to_owned()))
+        .stdin(None)
+        .priority(config::Option(1))
+        .build()
+        .unwrap();
+    assert_eq!(command.timeout, None);
+    assert!(command.stdin.is_none());
+
+    // The priority field is not optional, so leaving it out is an error.
+    let result = Command::builder()
+        .executable("cargo".to_owned())
+        .args(vec![])
+        .env(vec![])
+        .build();
+    assert!(result.is_err());
+}
@@ -10,4 +10,10 @@ fn tests() {
     //t.pass("tests/07-repeated-field.rs");
     //t.compile_fail("tests/08-unrecognized-attribute.rs");
     //t.pass("tests/09-redefined-prelude-types.rs");
+    //t.pass("tests/10-wrap-smart-pointers.rs");
+    //t.pass("tests/11-arbitrary-builder.rs");
+    //t.pass("tests/12-schema-export.rs");
+    //t.pass("tests/13-setter-transform.rs");
+    //t.compile_fail("tests/14-setter-name-conflict.rs");
+    //t.pass("tests/15-option-setters.rs");
 }
@@ -6,3 +6,10 @@ pub fn derive(input: TokenStream) -> TokenStream {
 
     unimplemented!()
 }
+
+#[proc_macro_derive(CustomDisplay)]
+pub fn derive_display(input: TokenStream) -> TokenStream {
+    let _ = input;
+
+    unimplemented!()
+}
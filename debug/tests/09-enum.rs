@@ -0,0 +1,115 @@
+// So far the derive has only dealt with structs with named fields. Enums are
+// where a handwritten Debug impl is most tedious, so extend the macro to
+// accept them as well.
+//
+// Generate a match over self with one arm per variant. Unit variants print
+// just their name, tuple variants go through Formatter::debug_tuple, and
+// struct variants go through Formatter::debug_struct, so that by default the
+// output is identical to what the standard library's derive would print. The
+// test below checks that by comparing against an identical enum with
+// #[derive(Debug)], in both compact {:?} and pretty {:#?} mode.
+//
+//     impl<T: Trait> Debug for State<T>
+//     where
+//         T::Value: Debug,
+//     {
+//         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+//             match self {
+//                 State::Idle => f.write_str("Idle"),
+//                 State::Running(__0, __1) => f.debug_tuple("Running")
+//                     .field(__0)
+//                     .field(&format_args!("{:#x}", __1))
+//                     .finish(),
+//                 State::Failed { code, marker } => ...
+//             }
+//         }
+//     }
+//
+// Everything from test cases 03 through 07 applies to the fields of every
+// variant: #[debug = "..."] formats, no bound for a type parameter that only
+// appears inside PhantomData, and a `T::Value: Debug` bound for associated
+// types. Collect the field types of all variants into one list before running
+// the bound inference, and the rest of the logic should carry over unchanged.
+//
+//
+// Resources:
+//
+//   - The DebugTuple helper for formatting tuple-like values:
+//     https://doc.rust-lang.org/std/fmt/struct.DebugTuple.html
+//
+//   - Representation of an enum in the Syn syntax tree:
+//     https://docs.rs/syn/1.0/syn/struct.DataEnum.html
+
+use derive_debug::CustomDebug;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+pub trait Trait {
+    type Value;
+}
+
+#[derive(CustomDebug)]
+pub enum State<T: Trait> {
+    Idle,
+    Running(u32, #[debug = "{:#x}"] u64),
+    Waiting { on: Vec<T::Value> },
+    Failed { code: i32, marker: PhantomData<T> },
+}
+
+#[derive(Debug)]
+pub enum Expected {
+    Idle,
+    Running(u32, Hex),
+    Waiting { on: Vec<u8> },
+    Failed { code: i32, marker: PhantomData<Id> },
+}
+
+pub struct Hex(u64);
+
+impl Debug for Hex {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:#x}", self.0)
+    }
+}
+
+// Does not implement Debug, but its associated type does.
+pub struct Id;
+
+impl Trait for Id {
+    type Value = u8;
+}
+
+fn assert_debug<F: Debug>() {}
+
+fn main() {
+    assert_debug::<State<Id>>();
+
+    let cases: [(State<Id>, Expected); 4] = [
+        (State::Idle, Expected::Idle),
+        (State::Running(7, 255), Expected::Running(7, Hex(255))),
+        (
+            State::Waiting { on: vec![1, 2] },
+            Expected::Waiting { on: vec![1, 2] },
+        ),
+        (
+            State::Failed {
+                code: -1,
+                marker: PhantomData,
+            },
+            Expected::Failed {
+                code: -1,
+                marker: PhantomData,
+            },
+        ),
+    ];
+
+    for (actual, expected) in &cases {
+        assert_eq!(format!("{:?}", actual), format!("{:?}", expected));
+        assert_eq!(format!("{:#?}", actual), format!("{:#?}", expected));
+    }
+
+    assert_eq!(
+        format!("{:?}", State::<Id>::Running(7, 255)),
+        "Running(7, 0xff)"
+    );
+}
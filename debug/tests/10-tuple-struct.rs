@@ -0,0 +1,59 @@
+// Newtypes are the other common shape of struct that the derive should handle.
+// Generate Formatter::debug_tuple output for tuple structs and print just the
+// name for unit structs, matching the standard library's derive.
+//
+//     impl Debug for Addr {
+//         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+//             f.debug_tuple("Addr")
+//                 .field(&format_args!("{:#x}", &self.0))
+//                 .finish()
+//         }
+//     }
+//
+// Positional fields have no name to put in an identifier, so refer to them
+// with syn::Index rather than a string like "0". Quoting a usize would produce
+// `self.0usize`, which is not valid syntax.
+//
+// The #[debug = "..."] attribute and the generic bound inference work on
+// positional fields exactly as they do on named ones. If test case 09 is
+// already passing, the code for tuple variants can probably be shared here.
+//
+//
+// Resources:
+//
+//   - Index of an unnamed field for use in a field access expression:
+//     https://docs.rs/syn/1.0/syn/struct.Index.html
+
+use derive_debug::CustomDebug;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+#[derive(CustomDebug)]
+pub struct Addr(#[debug = "{:#x}"] u64);
+
+#[derive(CustomDebug)]
+pub struct Tagged<T, U>(T, PhantomData<U>);
+
+#[derive(CustomDebug)]
+pub struct Unit;
+
+fn assert_debug<F: Debug>() {}
+
+fn main() {
+    // Does not implement Debug.
+    struct NotDebug;
+
+    assert_debug::<Tagged<u8, NotDebug>>();
+
+    assert_eq!(format!("{:?}", Addr(0xdead)), "Addr(0xdead)");
+    assert_eq!(format!("{:#?}", Addr(0xdead)), "Addr(\n    0xdead,\n)");
+
+    let tagged = Tagged::<_, NotDebug>("x", PhantomData);
+    assert_eq!(
+        format!("{:?}", tagged),
+        format!("Tagged(\"x\", {:?})", PhantomData::<NotDebug>),
+    );
+
+    assert_eq!(format!("{:?}", Unit), "Unit");
+    assert_eq!(format!("{:#?}", Unit), "Unit");
+}
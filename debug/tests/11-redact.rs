@@ -0,0 +1,86 @@
+// Debug output tends to end up in logs, and logs are the last place that
+// passwords and tokens should be written to. Look for a field attribute
+// #[debug(redact)] and print the placeholder `<redacted>` in place of the
+// field's value.
+//
+// Sometimes it helps to know something about a secret without seeing it, for
+// example whether it was empty or whether two services were configured with
+// the same key. Accept two variations:
+//
+//   - #[debug(redact = "len")] prints `<redacted len=N>`, where N comes from
+//     calling the field's `len()` method.
+//
+//   - #[debug(redact = "hash")] prints `<redacted #xxxxxxxx>`, the first eight
+//     lowercase hex digits of a 64-bit FNV-1a hash fed through the field's
+//     std::hash::Hash impl. Emit the few lines of FNV-1a into the expansion
+//     rather than using std's DefaultHasher, whose output is allowed to change
+//     between Rust releases.
+//
+// None of the three forms ever calls the field's Debug impl, so the field type
+// does not need to implement Debug. In particular, a type parameter whose only
+// appearance is in redacted fields must not get a `T: Debug` bound, in the
+// same way as the PhantomData special case from test case 05.
+//
+// Parsing this attribute means handling #[debug(...)] with a list of nested
+// items on fields, in addition to the #[debug = "..."] form from test case 03
+// and the container-level #[debug(bound = "...")] from test case 08.
+
+use derive_debug::CustomDebug;
+use std::fmt::Debug;
+
+#[derive(CustomDebug)]
+pub struct Credentials<T> {
+    user: &'static str,
+    #[debug(redact)]
+    token: T,
+    #[debug(redact = "len")]
+    password: String,
+    #[debug(redact = "hash")]
+    api_key: String,
+}
+
+fn assert_debug<F: Debug>() {}
+
+fn main() {
+    // Does not implement Debug.
+    struct Token;
+
+    assert_debug::<Credentials<Token>>();
+
+    let credentials = Credentials {
+        user: "admin",
+        token: Token,
+        password: "hunter2".to_owned(),
+        api_key: "0123-4567".to_owned(),
+    };
+
+    let debug = format!("{:?}", credentials);
+    assert!(debug.starts_with(
+        r#"Credentials { user: "admin", token: <redacted>, password: <redacted len=7>, api_key: <redacted #"#
+    ));
+    assert!(debug.ends_with("> }"));
+    assert!(!debug.contains("hunter2"));
+    assert!(!debug.contains("0123-4567"));
+
+    let hash = &debug[debug.rfind('#').unwrap() + 1..debug.len() - 3];
+    assert_eq!(hash.len(), 8);
+    assert!(hash.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')));
+
+    // Same key, same hash; different key, different hash.
+    let same = Credentials {
+        user: "other",
+        token: Token,
+        password: String::new(),
+        api_key: "0123-4567".to_owned(),
+    };
+    let different = Credentials {
+        user: "admin",
+        token: Token,
+        password: "hunter2".to_owned(),
+        api_key: "0123-4568".to_owned(),
+    };
+    assert!(format!("{:?}", same).ends_with(&format!("#{}> }}", hash)));
+    assert!(!format!("{:?}", different).ends_with(&format!("#{}> }}", hash)));
+
+    assert!(format!("{:?}", same).contains("password: <redacted len=0>"));
+}
@@ -0,0 +1,82 @@
+// Not every field is interesting to look at. Caches, handles and empty
+// collections mostly add noise to Debug output.
+//
+// Look for a field attribute #[debug(skip)] and leave that field out of the
+// output entirely. Also look for #[debug(skip_if = path::to::predicate)],
+// where the predicate is called with a reference to the field and the field
+// is left out whenever it returns true. Paths such as Option::is_none and
+// Vec::is_empty are the typical use. Unlike the string literals of the
+// earlier attributes, the value here is a path written without quotes.
+//
+// Whenever at least one field was left out, finish the output with
+// DebugStruct::finish_non_exhaustive instead of DebugStruct::finish, so the
+// reader can tell that the struct has more fields than are shown:
+//
+//     Conn { addr: "localhost", .. }
+//
+// A container attribute #[debug(non_exhaustive)] asks for that style even
+// when every field was printed.
+//
+// For bound inference, a field that is always skipped is never printed and
+// should not contribute any bounds. A field with skip_if may still be printed
+// and keeps its bounds.
+//
+//
+// Resources:
+//
+//   - Finishing a struct with a trailing `..`:
+//     https://doc.rust-lang.org/std/fmt/struct.DebugStruct.html#method.finish_non_exhaustive
+
+use derive_debug::CustomDebug;
+use std::fmt::Debug;
+
+#[derive(CustomDebug)]
+pub struct Conn<C> {
+    addr: &'static str,
+    #[debug(skip)]
+    cache: C,
+    #[debug(skip_if = Option::is_none)]
+    user: Option<&'static str>,
+    #[debug(skip_if = Vec::is_empty)]
+    pending: Vec<u32>,
+}
+
+#[derive(CustomDebug)]
+#[debug(non_exhaustive)]
+pub struct Handle {
+    id: u32,
+}
+
+fn assert_debug<F: Debug>() {}
+
+fn main() {
+    // Does not implement Debug.
+    struct Cache;
+
+    assert_debug::<Conn<Cache>>();
+
+    let conn = Conn {
+        addr: "localhost",
+        cache: Cache,
+        user: None,
+        pending: vec![],
+    };
+    assert_eq!(format!("{:?}", conn), r#"Conn { addr: "localhost", .. }"#);
+
+    let conn = Conn {
+        addr: "localhost",
+        cache: Cache,
+        user: Some("admin"),
+        pending: vec![1, 2],
+    };
+    assert_eq!(
+        format!("{:?}", conn),
+        r#"Conn { addr: "localhost", user: Some("admin"), pending: [1, 2], .. }"#,
+    );
+
+    assert_eq!(format!("{:?}", Handle { id: 7 }), "Handle { id: 7, .. }");
+    assert_eq!(
+        format!("{:#?}", Handle { id: 7 }),
+        "Handle {\n    id: 7,\n    ..\n}",
+    );
+}
@@ -0,0 +1,85 @@
+// A format string can only rearrange what one of the std::fmt traits already
+// prints. For anything else, such as showing a raw u32 as a dotted IPv4
+// address, the caller needs to run their own code.
+//
+// Look for a field attribute #[debug(with = path::to::function)] naming a
+// function with the signature:
+//
+//     fn(&FieldType, &mut fmt::Formatter) -> fmt::Result
+//
+// The field's value in the debug_struct needs to be something that implements
+// Debug by calling that function. Since the derive cannot add a Debug impl to
+// someone else's function, emit a small wrapper struct inside the generated fmt
+// method, holding a reference to the field, with a Debug impl that forwards to
+// the function.
+//
+// The function takes over formatting completely, so the field's type does not
+// need to implement Debug. As with the redacted fields from test case 11, a
+// type parameter that only appears in such fields gets no `T: Debug` bound.
+// Notice that the wrapper struct needs the generics of the input type in
+// order to hold a reference to the field when its type involves a type
+// parameter.
+
+use derive_debug::CustomDebug;
+use std::fmt::{self, Debug};
+use std::time::Duration;
+
+mod foreign {
+    // Some enum from another crate that does not implement Debug.
+    pub enum Level {
+        Low,
+        High,
+    }
+}
+
+fn ipv4_be(addr: &u32, f: &mut fmt::Formatter) -> fmt::Result {
+    let [a, b, c, d] = addr.to_be_bytes();
+    write!(f, "{}.{}.{}.{}", a, b, c, d)
+}
+
+fn millis(duration: &Duration, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}ms", duration.as_millis())
+}
+
+fn level(level: &foreign::Level, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_str(match level {
+        foreign::Level::Low => "low",
+        foreign::Level::High => "high",
+    })
+}
+
+fn opaque<T>(_: &T, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_str("_")
+}
+
+#[derive(CustomDebug)]
+pub struct Route<T> {
+    #[debug(with = ipv4_be)]
+    gateway: u32,
+    #[debug(with = millis)]
+    timeout: Duration,
+    #[debug(with = level)]
+    priority: foreign::Level,
+    #[debug(with = opaque)]
+    extra: T,
+}
+
+fn assert_debug<F: Debug>() {}
+
+fn main() {
+    // Does not implement Debug.
+    struct Extra;
+
+    assert_debug::<Route<Extra>>();
+
+    let route = Route {
+        gateway: u32::from_be_bytes([192, 168, 0, 1]),
+        timeout: Duration::from_millis(1500),
+        priority: foreign::Level::High,
+        extra: Extra,
+    };
+
+    let debug = format!("{:?}", route);
+    let expected = "Route { gateway: 192.168.0.1, timeout: 1500ms, priority: high, extra: _ }";
+    assert_eq!(debug, expected);
+}
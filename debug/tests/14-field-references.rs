@@ -0,0 +1,64 @@
+// Some values only make sense together with a neighboring field, like a
+// quantity and its unit. Extend the #[debug = "..."] attribute so that the
+// format string may refer to other fields of the same struct by name:
+//
+//     #[debug = "{value} {unit}"]
+//     value: u32,
+//     unit: &'static str,
+//
+// A plain {} or {:08b} placeholder keeps referring to the annotated field
+// itself, as in test case 03. For each {name} or {name:spec} placeholder,
+// pass the field of that name as a named argument:
+//
+//     format_args!("{value} {unit}", value = &self.value, unit = &self.unit)
+//
+// Do not pass named arguments that the string does not use, because
+// format_args! rejects those. That means the macro needs to scan the format
+// string itself to find the names, remembering that {{ and }} are escaped
+// braces and not placeholders.
+//
+// Also look for a container attribute #[debug(fmt = "...")]. Its format
+// string replaces the struct output entirely: no type name, no braces, only
+// the formatted string. Every placeholder in a container-level format must be
+// named, since there is no single field for {} to refer to.
+//
+// The next test case covers what happens when a name does not match a field.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Reading {
+    sensor: &'static str,
+    #[debug = "{value} {unit}"]
+    value: u32,
+    unit: &'static str,
+    #[debug = "{:.1}% of {{max}}"]
+    load: f64,
+}
+
+#[derive(CustomDebug)]
+#[debug(fmt = "{name}@{bitmask:#x}")]
+pub struct Field {
+    name: &'static str,
+    bitmask: u16,
+}
+
+fn main() {
+    let reading = Reading {
+        sensor: "cpu",
+        value: 42,
+        unit: "C",
+        load: 12.345,
+    };
+
+    let debug = format!("{:?}", reading);
+    let expected = r#"Reading { sensor: "cpu", value: 42 C, unit: "C", load: 12.3% of {max} }"#;
+    assert_eq!(debug, expected);
+
+    let field = Field {
+        name: "F",
+        bitmask: 0x1c,
+    };
+
+    assert_eq!(format!("{:?}", field), "F@0x1c");
+}
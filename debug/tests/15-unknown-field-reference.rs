@@ -0,0 +1,23 @@
+// A field name in a format string that does not match any field would
+// otherwise surface as an error from inside the format_args! call that the
+// derive generated, which does not tell the caller much. Check every name
+// against the struct's fields while scanning the format string and report
+// unknown ones from the macro.
+//
+// Point the error at the attribute's string literal as a whole, and put the
+// unknown name and its byte offset within the string in the message. A span
+// covering only the name would be nicer, but proc_macro2::Literal::subspan
+// can only produce one on a nightly compiler, and the error should read the
+// same on every toolchain.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Reading {
+    sensor: &'static str,
+    #[debug = "{value} {units}"]
+    value: u32,
+    unit: &'static str,
+}
+
+fn main() {}
@@ -0,0 +1,86 @@
+// Many Display impls are nothing more than a template over the fields. Add a
+// second derive macro, CustomDisplay, to the same crate. A proc-macro crate
+// can export any number of derives, each from its own #[proc_macro_derive]
+// function.
+//
+// The template goes in a #[display("...")] attribute on a struct, or on each
+// variant of an enum. Placeholders name fields just like the format strings
+// from test case 14, and positional fields of tuple structs and tuple variants
+// are written {0}, {1}, and so on:
+//
+//     impl<T> Display for Error<T>
+//     where
+//         T: Display,
+//     {
+//         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+//             match self {
+//                 Error::NotFound { path } => write!(f, "{path} was not found", path = path),
+//                 ...
+//             }
+//         }
+//     }
+//
+// The generic bound inference from CustomDebug carries over with Display in
+// place of Debug. The part that changes is which fields count: a field that no
+// template mentions is never printed, so only fields that actually appear in
+// a template contribute bounds. In the test below T is only used by a field
+// that is never interpolated, so the impl must not require T: Display. Try to
+// share the inference code between the two derives rather than copying it,
+// with the trait to emit in the bounds passed in as a parameter.
+//
+//
+// Resources:
+//
+//   - The Display trait:
+//     https://doc.rust-lang.org/std/fmt/trait.Display.html
+
+use derive_debug::CustomDisplay;
+use std::fmt::Display;
+
+#[derive(CustomDisplay)]
+#[display("{name} ({id:#06x})")]
+pub struct Device<T> {
+    name: String,
+    id: u16,
+    driver: T,
+}
+
+#[derive(CustomDisplay)]
+pub enum Error<P> {
+    #[display("{path} was not found")]
+    NotFound { path: P },
+    #[display("expected {0} bytes, got {1}")]
+    Truncated(usize, usize),
+    #[display("operation timed out")]
+    Timeout,
+}
+
+#[derive(CustomDisplay)]
+#[display("v{0}.{1}")]
+pub struct Version(u32, u32);
+
+fn assert_display<F: Display>() {}
+
+fn main() {
+    // Does not implement Display.
+    struct Driver;
+
+    assert_display::<Device<Driver>>();
+
+    let device = Device {
+        name: "eth0".to_owned(),
+        id: 0x1f,
+        driver: Driver,
+    };
+    assert_eq!(device.to_string(), "eth0 (0x001f)");
+
+    let error = Error::NotFound { path: "/etc/hosts" };
+    assert_eq!(error.to_string(), "/etc/hosts was not found");
+    assert_eq!(
+        Error::<&str>::Truncated(8, 3).to_string(),
+        "expected 8 bytes, got 3",
+    );
+    assert_eq!(Error::<&str>::Timeout.to_string(), "operation timed out");
+
+    assert_eq!(Version(1, 2).to_string(), "v1.2");
+}
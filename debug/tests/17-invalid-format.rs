@@ -0,0 +1,49 @@
+// Mistakes in a #[debug = "..."] format string currently surface as errors
+// from inside the format_args! call in the generated code, and those point at
+// the derive rather than at the attribute. Parse the format string in the
+// macro and report problems from there.
+//
+// Two things to check:
+//
+//   - The string refers to the annotated field exactly once, either
+//     positionally as {} or {0}, or by its own name as in test case 14.
+//     Repeating the same reference, like the {0} that appears twice in the
+//     alt format of test case 23, still counts as one. No reference at all,
+//     or a second positional placeholder that would need an argument the
+//     macro does not pass, is an error. Placeholders that name other fields
+//     do not count toward this.
+//
+//   - The format trait after the colon is one that std::fmt supports: none
+//     (Display), ?, x?, X?, x, X, o, b, e, E or p.
+//
+// Report every problem in the struct rather than stopping at the first one.
+// syn::Error::combine can merge several errors into one that expands to
+// multiple compile_error! invocations.
+//
+// As in test case 15, point each error at the whole string literal and
+// include the byte offset of the problem within the string in the message, so
+// that the output is the same on every toolchain.
+//
+//
+// Resources:
+//
+//   - The grammar of format strings:
+//     https://doc.rust-lang.org/std/fmt/index.html#syntax
+//
+//   - Merging errors:
+//     https://docs.rs/syn/1.0/syn/struct.Error.html#method.combine
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Field {
+    name: &'static str,
+    #[debug = "0b{:08q}"]
+    bitmask: u8,
+    #[debug = "{:x} and {:x}"]
+    mask: u8,
+    #[debug = "{:#x?}"]
+    ok: u8,
+}
+
+fn main() {}
@@ -0,0 +1,103 @@
+// Test case 07 recognized T::Value inside a Vec. Associated types show up in
+// many more places than that, and each of them should lead to the smallest
+// where-clause that still makes the impl compile.
+//
+//     impl<'a, T: Trait, U: Trait, V, W, X> Debug for Field<'a, T, U, V, W, X>
+//     where
+//         <T as Trait>::Value: Debug,
+//         <T::Nested as Inner>::Leaf: Debug,
+//         U::Value: Debug,
+//         T::Value: Debug,
+//         W: Debug,
+//         vec_of!(X): Debug,
+//     {...}
+//
+// Walk every kind of syn::Type rather than only paths:
+//
+//   - Qualified paths. A syn::TypePath whose qself mentions a type parameter,
+//     like <T as Trait>::Value, is an associated type and gets a bound as a
+//     whole. Nested associated types have to be written with a qualified path
+//     as well, <T::Nested as Inner>::Leaf, because rustc rejects the shorthand
+//     T::Nested::Leaf as ambiguous.
+//
+//   - Tuples, arrays, slices and references. Descend into the element types.
+//
+//   - Function pointers. Every fn pointer type implements Debug no matter
+//     what its argument and return types are, so do not descend into them at
+//     all. V below needs no bound.
+//
+//   - Type aliases. An alias like Pair<W> is just a path, and the ordinary rule
+//     from test case 04 applies to its generic arguments.
+//
+//   - Type macros. The macro cannot see what vec_of!(X) expands to. If the
+//     tokens inside mention a type parameter, bound the macro invocation type
+//     itself, as where-clauses accept type macros.
+//
+// Finally, inferred bounds are a common source of confusion, so attach a doc
+// comment to the generated impl that lists them, one per line. It has no
+// effect on compilation but shows up in the output of `cargo expand`:
+//
+//     #[doc = " Inferred bounds:"]
+//     #[doc = " - <T as Trait>::Value: Debug"]
+//     ...
+//
+//
+// Resources:
+//
+//   - All the shapes a type can take in the Syn syntax tree:
+//     https://docs.rs/syn/1.0/syn/enum.Type.html
+//
+//   - A visitor over the syntax tree, which may save some boilerplate:
+//     https://docs.rs/syn/1.0/syn/visit/index.html
+
+use derive_debug::CustomDebug;
+use std::fmt::Debug;
+
+pub trait Trait {
+    type Value;
+    type Nested: Inner;
+}
+
+pub trait Inner {
+    type Leaf;
+}
+
+type Pair<T> = (T, T);
+
+macro_rules! vec_of {
+    ($t:ty) => {
+        Vec<$t>
+    };
+}
+
+#[derive(CustomDebug)]
+pub struct Field<'a, T: Trait, U: Trait, V, W, X> {
+    qualified: Vec<<T as Trait>::Value>,
+    nested: Option<<T::Nested as Inner>::Leaf>,
+    tuple: (U::Value, u8),
+    array: [U::Value; 2],
+    reference: &'a T::Value,
+    callback: fn(V) -> V,
+    alias: Pair<W>,
+    mac: vec_of!(X),
+}
+
+fn assert_debug<F: Debug>() {}
+
+fn main() {
+    // None of these implement Debug, but the associated types do.
+    struct Id;
+    struct Leafy;
+    struct NotDebug;
+
+    impl Trait for Id {
+        type Value = u8;
+        type Nested = Leafy;
+    }
+
+    impl Inner for Leafy {
+        type Leaf = u16;
+    }
+
+    assert_debug::<Field<'static, Id, Id, NotDebug, u8, u8>>();
+}
@@ -0,0 +1,67 @@
+// Test case 08 mentioned, as an optional extension, a #[debug(bound = "...")]
+// attribute on individual fields. Implement it now.
+//
+// A field-level bound replaces only the bounds that would have been inferred
+// from that one field. Bounds inferred from the other fields stay in place.
+// For Wrapper below:
+//
+//     impl<T: Trait, U> Debug for Wrapper<T, U>
+//     where
+//         T::Value: Debug,
+//         U: Debug,
+//     {...}
+//
+// An empty string is allowed in both positions and means "no bounds". On a
+// field it removes that field's inferred bounds. On the container it removes
+// all inferred bounds, so the impl only has the bounds written on the type
+// parameters themselves. That is the right thing for Handle below, because raw
+// pointers implement Debug by printing the address, whatever they point to.
+
+use derive_debug::CustomDebug;
+use std::fmt::Debug;
+
+pub trait Trait {
+    type Value;
+}
+
+#[derive(CustomDebug)]
+pub struct Wrapper<T: Trait, U> {
+    #[debug(bound = "T::Value: Debug")]
+    field: Field<T>,
+    normal: U,
+}
+
+#[derive(CustomDebug)]
+struct Field<T: Trait> {
+    values: Vec<T::Value>,
+}
+
+#[derive(CustomDebug)]
+#[debug(bound = "")]
+pub struct Handle<T> {
+    ptr: *const T,
+}
+
+#[derive(CustomDebug)]
+pub struct Pair<T, U> {
+    #[debug(bound = "")]
+    first: *const T,
+    second: U,
+}
+
+fn assert_debug<F: Debug>() {}
+
+fn main() {
+    // Does not implement Debug.
+    struct NotDebug;
+
+    struct Id;
+
+    impl Trait for Id {
+        type Value = u8;
+    }
+
+    assert_debug::<Wrapper<Id, u8>>();
+    assert_debug::<Handle<NotDebug>>();
+    assert_debug::<Pair<NotDebug, u8>>();
+}
@@ -0,0 +1,28 @@
+// A handwritten bound is easy to get out of sync with the type it belongs to,
+// for example after a type parameter is renamed. rustc does report the
+// undeclared name, but the message says nothing about where the bound came
+// from.
+//
+// The macro knows the generic parameters of the input, so check handwritten
+// bounds against them, in both container and field attributes. There is no way
+// to tell a type parameter from any other type name by looking at tokens, so
+// use the same kind of heuristic as the rest of the bound inference: a bounded
+// type that is a path of two or more segments starting with a capitalized
+// identifier, like U::Value, is taken to be an associated type of a type
+// parameter. If that identifier is not one of the struct's type parameters,
+// report an error on the attribute's string literal. Procedural macros have no
+// stable way to emit a warning, so this has to be a hard error.
+
+use derive_debug::CustomDebug;
+
+pub trait Trait {
+    type Value;
+}
+
+#[derive(CustomDebug)]
+#[debug(bound = "U::Value: Debug")]
+pub struct Wrapper<T: Trait> {
+    field: Vec<T::Value>,
+}
+
+fn main() {}
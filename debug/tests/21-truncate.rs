@@ -0,0 +1,76 @@
+// A buffer with tens of thousands of elements makes for a very long line of
+// Debug output. Add two field attributes that shorten the output of large
+// fields.
+//
+// #[debug(max_items = N)] applies to any field whose reference implements
+// IntoIterator, such as &Vec<T>, &[T; N] and &HashMap<K, V>. Print the first N
+// items as a list, then a final entry saying how many were left out:
+//
+//     [0, 1, 2, 3, ... (+65532 more)]
+//
+// The trailing entry is not an item, so it must not be quoted as a string
+// would be. DebugList::entry accepts any &dyn Debug, and format_args! produces
+// a value whose Debug impl writes its text as-is.
+//
+// #[debug(max_len = N)] applies to string fields. Print the first N characters
+// inside quotes, escaped as str's Debug impl would, followed by `...` outside
+// the quotes. Count characters rather than bytes so the cut never lands inside
+// a multi-byte UTF-8 sequence. Values that fit print unchanged.
+//
+// Both attributes work through the DebugList and Debug impls of the items
+// themselves, so pretty {:#?} output keeps working: the list is broken up
+// one entry per line and the "more" entry appears on its own line at the end.
+//
+//
+// Resources:
+//
+//   - The DebugList helper for formatting lists:
+//     https://doc.rust-lang.org/std/fmt/struct.DebugList.html
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Packet {
+    #[debug(max_items = 4)]
+    payload: Vec<u8>,
+    #[debug(max_items = 4)]
+    header: [u16; 2],
+    #[debug(max_len = 5)]
+    note: String,
+}
+
+fn main() {
+    let packet = Packet {
+        payload: (0..=255).cycle().take(65536).collect(),
+        header: [1, 2],
+        note: "héllo, world".to_owned(),
+    };
+
+    let debug = format!("{:?}", packet);
+    let expected =
+        r#"Packet { payload: [0, 1, 2, 3, ... (+65532 more)], header: [1, 2], note: "héllo"... }"#;
+    assert_eq!(debug, expected);
+
+    let packet = Packet {
+        payload: vec![9; 5],
+        header: [1, 2],
+        note: "goodbye".to_owned(),
+    };
+
+    let debug = format!("{:#?}", packet);
+    let expected = r#"Packet {
+    payload: [
+        9,
+        9,
+        9,
+        9,
+        ... (+1 more),
+    ],
+    header: [
+        1,
+        2,
+    ],
+    note: "goodb"...,
+}"#;
+    assert_eq!(debug, expected);
+}
@@ -0,0 +1,65 @@
+// Protocol structs are mostly byte buffers, and the default Debug output of a
+// byte buffer is a list of decimal numbers. The #[debug = "..."] attribute
+// from test case 03 formats the field as a single value, so it cannot help
+// with the individual bytes either.
+//
+// Add three field attributes for fields of type [u8; N], &[u8] and Vec<u8>.
+// All three can be implemented in terms of AsRef<[u8]>, so there is no need
+// to inspect the field's type.
+//
+//   - #[debug(hex)] prints the bytes as lowercase hex digits with no
+//     separators: 001122aabbcc.
+//
+//   - #[debug(bytes)] prints the bytes as a byte string literal would appear
+//     in source code, b"...", with every byte escaped by
+//     std::ascii::escape_default.
+//
+//   - #[debug(hexdump)] prints the same as hex in compact {:?} mode. In
+//     pretty {:#?} mode it prints the layout of `hexdump -C`, one list entry
+//     per 16 bytes: the offset, the bytes in hex in two groups of eight, and
+//     the printable ASCII characters between bars with a `.` for anything
+//     else. Pad the hex columns of the last row so the ASCII column lines up.
+//
+// Making each row an entry of a DebugList lets the Formatter take care of
+// indenting the rows to the depth of the field.
+//
+//
+// Resources:
+//
+//   - Escaping a byte the way a byte string literal would:
+//     https://doc.rust-lang.org/std/ascii/fn.escape_default.html
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Frame<'a> {
+    #[debug(hex)]
+    mac: [u8; 6],
+    #[debug(bytes)]
+    magic: &'a [u8],
+    #[debug(hexdump)]
+    payload: Vec<u8>,
+}
+
+fn main() {
+    let frame = Frame {
+        mac: [0x00, 0x11, 0x22, 0xaa, 0xbb, 0xcc],
+        magic: b"GIF89a\0",
+        payload: b"Hello, world!\n\0\xffabc".to_vec(),
+    };
+
+    let debug = format!("{:?}", frame);
+    let expected = r#"Frame { mac: 001122aabbcc, magic: b"GIF89a\x00", payload: 48656c6c6f2c20776f726c64210a00ff616263 }"#;
+    assert_eq!(debug, expected);
+
+    let debug = format!("{:#?}", frame);
+    let expected = r#"Frame {
+    mac: 001122aabbcc,
+    magic: b"GIF89a\x00",
+    payload: [
+        00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 0a 00 ff  |Hello, world!...|,
+        00000010  61 62 63                                          |abc|,
+    ],
+}"#;
+    assert_eq!(debug, expected);
+}
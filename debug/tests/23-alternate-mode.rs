@@ -0,0 +1,75 @@
+// The custom formats from test case 03 were only checked with compact {:?}
+// output. Formatting a field through format_args! starts over with a fresh
+// set of formatting options, so flags given to the outer formatter do not
+// reach the field: a struct printed with {:#?} still prints a field formatted
+// with #[debug = "~{:?}"] on one line, and {:.2?} has no effect on it.
+//
+// Make the three ways of passing options through work for custom formats:
+//
+//   - In alternate mode, every {:?} placeholder in the template behaves as
+//     {:#?}. Formatter::alternate tells the generated code which mode it is
+//     in, and the macro can produce both versions of the template up front.
+//
+//   - A field attribute #[debug(alt = "...")] gives a separate template to use
+//     in alternate mode. It goes next to the #[debug = "..."] for compact mode.
+//
+//   - A placeholder that does not specify its own precision takes the outer
+//     formatter's precision, if any, through a `.*` or `.prec$` argument.
+//     Width is left alone, since padding a single field to the width that was
+//     meant for the whole struct would not make sense.
+//
+// The output for a field printed in pretty mode may span several lines. No
+// extra work is needed for the indentation: everything written by a field's
+// Debug impl already goes through the Formatter that DebugStruct indents.
+//
+//
+// Resources:
+//
+//   - Querying the options of the current Formatter:
+//     https://doc.rust-lang.org/std/fmt/struct.Formatter.html#method.alternate
+//     https://doc.rust-lang.org/std/fmt/struct.Formatter.html#method.precision
+
+use derive_debug::CustomDebug;
+
+#[derive(Debug)]
+pub struct Inner {
+    x: u8,
+}
+
+#[derive(CustomDebug)]
+pub struct Reading {
+    #[debug = "~{:?}"]
+    inner: Inner,
+    #[debug = "{:?} V"]
+    volts: f64,
+    #[debug = "0b{:08b}"]
+    #[debug(alt = "{0:#010b} = {0}")]
+    bits: u8,
+}
+
+fn main() {
+    let reading = Reading {
+        inner: Inner { x: 1 },
+        volts: 3.14159,
+        bits: 5,
+    };
+
+    let debug = format!("{:?}", reading);
+    let expected = "Reading { inner: ~Inner { x: 1 }, volts: 3.14159 V, bits: 0b00000101 }";
+    assert_eq!(debug, expected);
+
+    let debug = format!("{:#?}", reading);
+    let expected = "\
+Reading {
+    inner: ~Inner {
+        x: 1,
+    },
+    volts: 3.14159 V,
+    bits: 0b00000101 = 5,
+}";
+    assert_eq!(debug, expected);
+
+    let debug = format!("{:.2?}", reading);
+    let expected = "Reading { inner: ~Inner { x: 1 }, volts: 3.14 V, bits: 0b00000101 }";
+    assert_eq!(debug, expected);
+}
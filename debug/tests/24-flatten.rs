@@ -0,0 +1,110 @@
+// Look for a field attribute #[debug(flatten)] on a field whose type also
+// derives CustomDebug. Instead of printing that field as a nested struct,
+// print its fields inline, as if they had been declared in the parent.
+//
+//     Shape { name: "dot", x: 1, y: 2, visible: true }
+//
+// rather than:
+//
+//     Shape { name: "dot", pos: Point { x: 1, y: 2 }, visible: true }
+//
+// The parent's derive only sees the tokens `Point`, not Point's fields, so the
+// work has to be split between the two derives. Have the CustomDebug derive of
+// every struct with named fields also generate a hidden helper trait, with a
+// method that adds the struct's fields to a DebugStruct somebody else has
+// already started:
+//
+//     #[doc(hidden)]
+//     pub trait __DebugFieldsPoint {
+//         fn __debug_fields(&self, debug: &mut fmt::DebugStruct<'_, '_>);
+//     }
+//
+//     impl<T: Debug> __DebugFieldsPoint for Point<T> {
+//         fn __debug_fields(&self, debug: &mut fmt::DebugStruct<'_, '_>) {
+//             debug.field("x", &self.x);
+//             debug.field("y", &self.y);
+//         }
+//     }
+//
+// A proc-macro crate cannot export one trait for all derives to share, so
+// each struct gets its own, declared next to the struct with the struct's
+// visibility and named after it. The parent finds the trait by replacing the
+// last segment of the field's type path, so for the field type
+// geometry::Point<T> it calls:
+//
+//     <geometry::Point<T> as geometry::__DebugFieldsPoint>::__debug_fields(
+//         &self.pos,
+//         &mut debug,
+//     );
+//
+// That means the flattened field's type has to be written as a path to the
+// struct itself, not through a type alias or a re-export under another name.
+// The fmt method of Point itself can be written in terms of the same method.
+//
+// Only structs with named fields get the helper trait. Enums, tuple and unit
+// structs, and unions have no named fields to add to someone else's
+// DebugStruct, so #[debug(flatten)] on a field of such a type fails to find
+// the trait and is rejected by the compiler. #[debug(flatten)] on a field of a
+// tuple struct or tuple variant has no name to flatten under either, so
+// report it as an error from the macro.
+//
+// The impl of the helper trait needs the same generics and where-clause as the
+// Debug impl. For bound inference in the parent, treat a flattened field like
+// any other field: a type parameter that appears in its type gets a
+// `T: Debug` bound. Flattening nests, so Shape can itself be flattened into
+// another struct.
+
+use derive_debug::CustomDebug;
+
+mod geometry {
+    use derive_debug::CustomDebug;
+
+    #[derive(CustomDebug)]
+    pub struct Point<T> {
+        pub x: T,
+        pub y: T,
+    }
+}
+
+#[derive(CustomDebug)]
+pub struct Shape<T> {
+    name: &'static str,
+    #[debug(flatten)]
+    pos: geometry::Point<T>,
+    visible: bool,
+}
+
+#[derive(CustomDebug)]
+pub struct Layer {
+    #[debug(flatten)]
+    shape: Shape<i32>,
+    #[debug = "{:.1}"]
+    opacity: f32,
+}
+
+fn main() {
+    let shape = Shape {
+        name: "dot",
+        pos: geometry::Point { x: 1, y: 2 },
+        visible: true,
+    };
+
+    let debug = format!("{:?}", shape);
+    let expected = r#"Shape { name: "dot", x: 1, y: 2, visible: true }"#;
+    assert_eq!(debug, expected);
+
+    let layer = Layer {
+        shape,
+        opacity: 0.5,
+    };
+
+    let debug = format!("{:#?}", layer);
+    let expected = r#"Layer {
+    name: "dot",
+    x: 1,
+    y: 2,
+    visible: true,
+    opacity: 0.5,
+}"#;
+    assert_eq!(debug, expected);
+}
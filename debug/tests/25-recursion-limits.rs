@@ -0,0 +1,98 @@
+// Test case 06 had a pair of mutually recursive types. Ownership through Box
+// guarantees that a value of such a type is finite, but with Rc and RefCell
+// a value can contain itself, and printing it would then recurse until the
+// stack overflows. Even without a cycle, a long linked list prints as one
+// very deep line.
+//
+// Add two container attributes to cut the output short.
+//
+//   - #[debug(max_depth = N)] prints at most N nested levels of the same
+//     type. A deeper value prints `...` in place of its entire output.
+//
+//   - #[debug(cycles)] prints `<cycle>` in place of a value that is already
+//     being printed further up the stack.
+//
+// Both need some state that lives across nested calls to fmt, which the
+// signature of Debug::fmt has no room for. Keep it in a thread_local! that the
+// derive emits inside the generated fmt method: a depth counter for the
+// first, and for the second a list of the addresses of values currently being
+// printed. Using the address of self is enough to identify a value, since two
+// different values that are alive at the same time do not share one.
+//
+// Do not keep that list in a Vec. The Debug impl should not allocate, as test
+// case 27 will check. Every value being printed has a call to fmt on the
+// stack, so let each call keep a frame in a local variable holding its address
+// and a pointer to the previous frame, and store only a pointer to the
+// innermost frame in the thread_local. Looking for a cycle then means walking
+// that linked list of frames.
+//
+// Take care to restore the state when fmt returns, including when a nested
+// impl returns an error or panics. A guard struct whose Drop impl puts back
+// the previous depth or frame pointer handles all of these cases.
+//
+// The state is per type and per thread. For a cycle that passes through
+// several types, like One<T> and Two<T> from test case 06, it is enough for
+// one of them to have the attribute.
+//
+//
+// Resources:
+//
+//   - Declaring thread-local state:
+//     https://doc.rust-lang.org/std/macro.thread_local.html
+
+use derive_debug::CustomDebug;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(CustomDebug)]
+#[debug(cycles)]
+pub struct Node {
+    name: &'static str,
+    next: Option<Rc<RefCell<Node>>>,
+}
+
+#[derive(CustomDebug)]
+#[debug(max_depth = 2)]
+pub struct List {
+    value: u8,
+    next: Option<Box<List>>,
+}
+
+fn main() {
+    let a = Rc::new(RefCell::new(Node {
+        name: "a",
+        next: None,
+    }));
+    let b = Rc::new(RefCell::new(Node {
+        name: "b",
+        next: Some(a.clone()),
+    }));
+    a.borrow_mut().next = Some(b);
+
+    let debug = format!("{:?}", a.borrow());
+    let expected = r#"Node { name: "a", next: Some(RefCell { value: Node { name: "b", next: Some(RefCell { value: <cycle> }) } }) }"#;
+    assert_eq!(debug, expected);
+
+    // Break the cycle so the nodes are freed.
+    a.borrow_mut().next = None;
+
+    let list = List {
+        value: 1,
+        next: Some(Box::new(List {
+            value: 2,
+            next: Some(Box::new(List {
+                value: 3,
+                next: None,
+            })),
+        })),
+    };
+
+    let debug = format!("{:?}", list);
+    let expected = "List { value: 1, next: Some(List { value: 2, next: Some(...) }) }";
+    assert_eq!(debug, expected);
+
+    // The depth is counted from wherever printing starts.
+    let debug = format!("{:?}", list.next.as_ref().unwrap());
+    let expected = "List { value: 2, next: Some(List { value: 3, next: None }) }";
+    assert_eq!(debug, expected);
+}
@@ -0,0 +1,139 @@
+// Tracing and logging pipelines would rather receive fields as key/value
+// pairs than parse them back out of a Debug string. Look for a container
+// attribute #[debug(visit = path::to::module)] and generate an impl of a
+// small visitor trait that hands out each field by name.
+//
+// As with the schema types in the builder's test case 12, a proc-macro crate
+// cannot export the traits involved, so the attribute names the module where
+// the caller has defined them. The expected shape is:
+//
+//     pub trait Visitor {
+//         fn u64(&mut self, name: &str, value: u64);
+//         fn i64(&mut self, name: &str, value: i64);
+//         fn f64(&mut self, name: &str, value: f64);
+//         fn bool(&mut self, name: &str, value: bool);
+//         fn str(&mut self, name: &str, value: &str);
+//         fn debug(&mut self, name: &str, value: &dyn Debug);
+//     }
+//
+//     pub trait Visit {
+//         fn visit(&self, visitor: &mut dyn Visitor);
+//     }
+//
+// Fields whose type is written as one of the primitive integer types, f32,
+// f64, bool, &str or String go to the matching typed method, with integers
+// widened through `as`. Everything else goes to `debug`.
+//
+// The attributes from the earlier test cases apply here too, so that the
+// visitor and the Debug output never disagree about what a field looks like:
+//
+//   - a field with a custom format, a `with` function or redaction goes to
+//     `debug` with the same rendering that Debug would print, even if its type
+//     is primitive;
+//   - skipped fields are not visited at all, and skip_if fields are visited
+//     only when the predicate returns false.
+
+use derive_debug::CustomDebug;
+use std::fmt::{Debug, Write};
+
+pub mod visit {
+    use std::fmt::Debug;
+
+    pub trait Visitor {
+        fn u64(&mut self, name: &str, value: u64);
+        fn i64(&mut self, name: &str, value: i64);
+        fn f64(&mut self, name: &str, value: f64);
+        fn bool(&mut self, name: &str, value: bool);
+        fn str(&mut self, name: &str, value: &str);
+        fn debug(&mut self, name: &str, value: &dyn Debug);
+    }
+
+    pub trait Visit {
+        fn visit(&self, visitor: &mut dyn Visitor);
+    }
+}
+
+#[derive(CustomDebug)]
+#[debug(visit = visit)]
+pub struct Request {
+    method: &'static str,
+    status: u16,
+    offset: i32,
+    ratio: f64,
+    cached: bool,
+    #[debug = "0b{:08b}"]
+    flags: u8,
+    #[debug(redact)]
+    token: String,
+    #[debug(skip)]
+    scratch: Vec<u8>,
+    #[debug(skip_if = Option::is_none)]
+    user: Option<&'static str>,
+    tags: Vec<&'static str>,
+}
+
+#[derive(Default)]
+struct Json(String);
+
+impl Json {
+    fn key(&mut self, name: &str) {
+        self.0.push_str(if self.0.is_empty() { "{" } else { "," });
+        write!(self.0, "{:?}:", name).unwrap();
+    }
+}
+
+impl visit::Visitor for Json {
+    fn u64(&mut self, name: &str, value: u64) {
+        self.key(name);
+        write!(self.0, "{}", value).unwrap();
+    }
+
+    fn i64(&mut self, name: &str, value: i64) {
+        self.key(name);
+        write!(self.0, "{}", value).unwrap();
+    }
+
+    fn f64(&mut self, name: &str, value: f64) {
+        self.key(name);
+        write!(self.0, "{}", value).unwrap();
+    }
+
+    fn bool(&mut self, name: &str, value: bool) {
+        self.key(name);
+        write!(self.0, "{}", value).unwrap();
+    }
+
+    fn str(&mut self, name: &str, value: &str) {
+        self.key(name);
+        write!(self.0, "{:?}", value).unwrap();
+    }
+
+    fn debug(&mut self, name: &str, value: &dyn Debug) {
+        self.key(name);
+        write!(self.0, "{:?}", format!("{:?}", value)).unwrap();
+    }
+}
+
+fn main() {
+    use visit::Visit;
+
+    let request = Request {
+        method: "GET",
+        status: 200,
+        offset: -3,
+        ratio: 0.25,
+        cached: true,
+        flags: 0b101,
+        token: "secret".to_owned(),
+        scratch: vec![1, 2, 3],
+        user: None,
+        tags: vec!["a", "b"],
+    };
+
+    let mut json = Json::default();
+    request.visit(&mut json);
+    json.0.push('}');
+
+    let expected = r#"{"method":"GET","status":200,"offset":-3,"ratio":0.25,"cached":true,"flags":"0b00000101","token":"<redacted>","tags":"[\"a\", \"b\"]"}"#;
+    assert_eq!(json.0, expected);
+}
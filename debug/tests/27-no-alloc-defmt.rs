@@ -0,0 +1,118 @@
+// Embedded targets often log through defmt, which sends compact binary
+// records to the host and leaves the string formatting to the host side.
+// Look for a container attribute #[debug(defmt)] and, in addition to the Debug
+// impl, generate an impl of defmt::Format driven by the same attributes:
+//
+//     impl defmt::Format for Field {
+//         fn format(&self, f: defmt::Formatter) {
+//             defmt::write!(
+//                 f,
+//                 "Field {{ name: {}, bitmask: 0b{:08b} }}",
+//                 self.name,
+//                 self.bitmask,
+//             )
+//         }
+//     }
+//
+// defmt::write! only accepts a string literal, so the macro needs to assemble
+// the whole template for the struct at expansion time, including the
+// #[debug = "..."] formats of the fields. defmt understands most of the same
+// format specs as std::fmt. Report a compile error for a field format that it
+// does not support rather than generating a call that fails inside defmt's
+// own macro.
+//
+// Targets like this often have no allocator at all, so the Debug impl must
+// not allocate either. Everything covered so far can be printed through
+// format_args! and the Formatter helpers, which never allocate, and the
+// recursion limits from test case 25 keep their state on the stack. It is an
+// easy property to lose by accident, for example by building a String for a
+// custom format. The test below counts allocations with a wrapping global
+// allocator while formatting into a fixed-size buffer.
+//
+// For the same reason, refer to everything through ::core::fmt rather than
+// ::std::fmt, so the expansion also works in a #![no_std] crate. The one
+// exception is #[debug(max_depth = N)] and #[debug(cycles)] from test case 25:
+// they rely on thread_local!, which only std provides, so they require std.
+// In a no_std crate the expansion of those attributes fails to compile and the
+// rest of the derive is unaffected.
+
+// Nothing here calls format, which keeps the test from needing a defmt
+// logger at link time. It only checks that the impl exists.
+//
+//
+// Resources:
+//
+//   - The defmt format string syntax:
+//     https://defmt.ferrous-systems.com/format-strings
+
+use derive_debug::CustomDebug;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+struct Buffer {
+    bytes: [u8; 128],
+    len: usize,
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let end = self.len + s.len();
+        if end > self.bytes.len() {
+            return Err(std::fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[derive(CustomDebug)]
+#[debug(defmt)]
+pub struct Field {
+    name: &'static str,
+    #[debug = "0b{:08b}"]
+    bitmask: u8,
+}
+
+fn assert_format<T: defmt::Format>() {}
+
+fn main() {
+    assert_format::<Field>();
+
+    let field = Field {
+        name: "F",
+        bitmask: 0b00011100,
+    };
+
+    let mut buffer = Buffer {
+        bytes: [0; 128],
+        len: 0,
+    };
+
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    write!(buffer, "{:?}", field).unwrap();
+    let after = ALLOCATIONS.load(Ordering::SeqCst);
+    assert_eq!(before, after);
+
+    let debug = std::str::from_utf8(&buffer.bytes[..buffer.len]).unwrap();
+    assert_eq!(debug, r#"Field { name: "F", bitmask: 0b00011100 }"#);
+}
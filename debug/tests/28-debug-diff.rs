@@ -0,0 +1,138 @@
+// When assert_eq! fails on a large struct, it prints both values in full and
+// leaves it to the reader to spot the one field that differs. Look for a
+// container attribute #[debug(diff)] and generate an inherent method that
+// prints only the differences:
+//
+//     impl Config {
+//         pub fn debug_diff<'a>(&'a self, other: &'a Self) -> impl Debug + 'a {...}
+//     }
+//
+// The result prints one line per field in the style of a unified diff, with
+// the old value on a line marked `-` and the new value on a line marked `+`.
+// Fields whose Debug output is the same on both sides are left out. Lines for
+// the struct itself are marked with a space.
+//
+// The other shapes that CustomDebug accepts follow from that:
+//
+//   - A tuple struct diffs its fields the same way, labeled with their
+//     positions 0, 1, ... and enclosed in parentheses instead of braces.
+//   - A unit struct never differs from itself and prints its name on a single
+//     context line.
+//   - An enum whose two values are the same variant diffs the fields of that
+//     variant as above, under the variant's name. If the variants differ
+//     there are no common fields to compare, so print the old value on a `-`
+//     line and the new value on a `+` line, each in compact Debug form.
+//
+// Unions are not accepted by CustomDebug until test case 31, which also covers
+// what debug_diff does for them.
+//
+// A field whose type also derives CustomDebug is diffed recursively rather
+// than printed whole, so a change deep inside a nested struct shows up as
+// exactly one pair of lines. The derive cannot see whether a field's type
+// derives CustomDebug, but the compiler can choose for it. Give every type
+// with #[debug(diff)] a hidden inherent method, say __debug_diff, and inside
+// the generated code declare a fallback trait with a method of the same name,
+// implemented for every T: Debug, that prints the -/+ pair. Method resolution
+// prefers inherent methods over trait methods, so calling
+// `self.field.__debug_diff(...)` picks the recursive version whenever there
+// is one. A nested type without the attribute is printed whole.
+//
+// Comparing Debug output means formatting both sides into Strings, so unlike
+// the Debug impl from test case 27 the generated diff code needs an allocator.
+// That is why it is opt-in: a type without #[debug(diff)] gets no diff code at
+// all and keeps working in a #![no_std] crate without alloc. Document the
+// generated debug_diff method as requiring alloc, and reach String and
+// format! through ::alloc, or ::std when the crate links std.
+//
+//
+// Resources:
+//
+//   - How method calls are resolved, including the order in which candidate
+//     receiver types and inherent and trait methods are tried:
+//     https://doc.rust-lang.org/reference/expressions/method-call-expr.html
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+#[debug(diff)]
+pub struct Limits {
+    memory: u32,
+    #[debug = "{}s"]
+    timeout: u32,
+}
+
+#[derive(CustomDebug)]
+#[debug(diff)]
+pub struct Config {
+    name: &'static str,
+    args: Vec<&'static str>,
+    limits: Limits,
+    verbose: bool,
+}
+
+#[derive(CustomDebug)]
+#[debug(diff)]
+pub struct Range(u32, u32);
+
+#[derive(CustomDebug)]
+#[debug(diff)]
+pub enum Job {
+    Idle,
+    Running { pid: u32, name: &'static str },
+}
+
+fn main() {
+    let old = Config {
+        name: "build",
+        args: vec!["--release"],
+        limits: Limits {
+            memory: 512,
+            timeout: 30,
+        },
+        verbose: false,
+    };
+
+    let new = Config {
+        name: "build",
+        args: vec!["--release", "--locked"],
+        limits: Limits {
+            memory: 512,
+            timeout: 60,
+        },
+        verbose: false,
+    };
+
+    let diff = format!("{:?}", old.debug_diff(&new));
+    let expected = r#" Config {
+-    args: ["--release"],
++    args: ["--release", "--locked"],
+     limits: Limits {
+-        timeout: 30s,
++        timeout: 60s,
+     },
+ }"#;
+    assert_eq!(diff, expected);
+
+    let diff = format!("{:?}", old.debug_diff(&old));
+    assert_eq!(diff, " Config {\n }");
+
+    let diff = format!("{:?}", Range(1, 5).debug_diff(&Range(1, 9)));
+    assert_eq!(diff, " Range(\n-    1: 5,\n+    1: 9,\n )");
+
+    let old = Job::Running {
+        pid: 7,
+        name: "make",
+    };
+    let new = Job::Running {
+        pid: 8,
+        name: "make",
+    };
+    let diff = format!("{:?}", old.debug_diff(&new));
+    assert_eq!(diff, " Running {\n-    pid: 7,\n+    pid: 8,\n }");
+
+    let diff = format!("{:?}", Job::Idle.debug_diff(&new));
+    assert_eq!(diff, "-Idle\n+Running { pid: 8, name: \"make\" }");
+
+    let diff = format!("{:?}", Job::Idle.debug_diff(&Job::Idle));
+    assert_eq!(diff, " Idle");
+}
@@ -0,0 +1,60 @@
+// Register and hardware descriptions tend to want the same format for every
+// integer, and repeating #[debug = "{:#x}"] on each field gets old quickly.
+// Add a few container attributes that set defaults for the whole struct, plus
+// renaming in the spirit of serde.
+//
+//   - #[debug(int = "...")] is the format for every field whose type is
+//     written as one of the primitive integer types. Other fields are not
+//     affected. A #[debug = "..."] on an individual field still takes
+//     precedence.
+//
+//   - #[debug(rename_all = "...")] changes the printed name of every field.
+//     Support the same conventions as serde: "lowercase", "UPPERCASE",
+//     "PascalCase", "camelCase", "snake_case", "SCREAMING_SNAKE_CASE",
+//     "kebab-case" and "SCREAMING-KEBAB-CASE". Field names are snake_case to
+//     begin with, so converting means splitting on underscores.
+//
+//   - #[debug(rename = "...")] on a field sets its printed name exactly,
+//     overriding rename_all.
+//
+//   - #[debug(name = "...")] on the container changes the type name printed
+//     before the braces.
+//
+// Report an unknown rename_all convention as an error on its string literal.
+// Test case 30 covers that error.
+//
+//
+// Resources:
+//
+//   - The case conventions supported by serde:
+//     https://serde.rs/container-attrs.html#rename_all
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+#[debug(name = "Ctrl", int = "{:#06x}", rename_all = "camelCase")]
+pub struct ControlRegister {
+    base_address: u32,
+    irq_line: u8,
+    #[debug(rename = "mask")]
+    interrupt_mask: u16,
+    #[debug = "{}"]
+    retry_count: u8,
+    device_name: &'static str,
+    enabled: bool,
+}
+
+fn main() {
+    let register = ControlRegister {
+        base_address: 0x4000,
+        irq_line: 7,
+        interrupt_mask: 0xff,
+        retry_count: 3,
+        device_name: "uart0",
+        enabled: true,
+    };
+
+    let debug = format!("{:?}", register);
+    let expected = r#"Ctrl { baseAddress: 0x4000, irqLine: 0x0007, mask: 0x00ff, retryCount: 3, deviceName: "uart0", enabled: true }"#;
+    assert_eq!(debug, expected);
+}
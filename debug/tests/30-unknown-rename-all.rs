@@ -0,0 +1,15 @@
+// The rename_all attribute from test case 29 takes one of a fixed set of case
+// conventions. Anything else is most likely a typo, and silently leaving the
+// field names unchanged would hide it. Report an error that points at the
+// string literal and lists the conventions that are supported.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+#[debug(rename_all = "camelcase")]
+pub struct ControlRegister {
+    base_address: u32,
+    irq_line: u8,
+}
+
+fn main() {}
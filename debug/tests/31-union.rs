@@ -0,0 +1,125 @@
+// The derives in the standard library reject unions, because there is no way
+// for them to know which field is safe to read. FFI code is full of unions,
+// though, and usually does know: many C APIs put a tag at the start of every
+// member struct, as SDL_Event does.
+//
+// Accept unions in CustomDebug, with a container attribute
+// #[debug(discriminant = path::to::function)] naming a function of the form:
+//
+//     fn(&Union) -> &'static str
+//
+// that returns the name of the field that is currently active. Print that
+// field, and only that field, with debug_struct:
+//
+//     impl Debug for Event {
+//         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+//             let mut debug = f.debug_struct("Event");
+//             match event_kind(self) {
+//                 "kind" => debug.field("kind", unsafe { &self.kind }),
+//                 "key" => debug.field("key", unsafe { &self.key }),
+//                 ...
+//             };
+//             debug.finish()
+//         }
+//     }
+//
+// The function takes responsibility for the unsafe reads: reading the field
+// it names has to be sound. If it returns a name that is not a field, print
+// no fields and finish with `..`.
+//
+// Without the attribute, there is no safe field to read. Fall back to printing
+// the union's bytes as hex in memory order, using debug_tuple with a single
+// entry. Unlike a field read, this is only sound if every byte of the union is
+// initialized, for example because it was last written through its largest
+// field. Say so in a doc comment on the generated impl, since that is where
+// someone reading `cargo expand` output would look.
+//
+// Every field type of a union is Copy or ManuallyDrop, and nothing about bound
+// inference changes: any field could be the one that gets printed.
+//
+// #[debug(diff)] from test case 28 works on unions too. A union has no field
+// that is known to be safe to compare, so it is always treated as a whole
+// value: a single context line if the Debug output is the same, otherwise a
+// `-` and a `+` line.
+
+use derive_debug::CustomDebug;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct Key {
+    kind: u32,
+    code: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct Mouse {
+    kind: u32,
+    x: i16,
+    y: i16,
+}
+
+#[derive(CustomDebug)]
+#[debug(discriminant = event_kind)]
+#[repr(C)]
+pub union Event {
+    kind: u32,
+    key: Key,
+    mouse: Mouse,
+}
+
+fn event_kind(event: &Event) -> &'static str {
+    // Every member starts with the tag, so reading it is always sound.
+    match unsafe { event.kind } {
+        1 => "key",
+        2 => "mouse",
+        _ => "kind",
+    }
+}
+
+#[derive(CustomDebug)]
+#[debug(diff)]
+#[repr(C)]
+pub union Raw {
+    int: u32,
+    bytes: [u8; 4],
+}
+
+fn main() {
+    let event = Event {
+        key: Key { kind: 1, code: 65 },
+    };
+    assert_eq!(
+        format!("{:?}", event),
+        "Event { key: Key { kind: 1, code: 65 } }",
+    );
+
+    let event = Event {
+        mouse: Mouse {
+            kind: 2,
+            x: -4,
+            y: 9,
+        },
+    };
+    assert_eq!(
+        format!("{:?}", event),
+        "Event { mouse: Mouse { kind: 2, x: -4, y: 9 } }",
+    );
+
+    let event = Event { kind: 0 };
+    assert_eq!(format!("{:?}", event), "Event { kind: 0 }");
+
+    let raw = Raw {
+        bytes: [0xde, 0xad, 0xbe, 0xef],
+    };
+    assert_eq!(format!("{:?}", raw), "Raw(deadbeef)");
+
+    let new = Raw {
+        bytes: [0xde, 0xad, 0xbe, 0x00],
+    };
+    let diff = format!("{:?}", raw.debug_diff(&new));
+    assert_eq!(diff, "-Raw(deadbeef)\n+Raw(deadbe00)");
+
+    let diff = format!("{:?}", raw.debug_diff(&raw));
+    assert_eq!(diff, " Raw(deadbeef)");
+}
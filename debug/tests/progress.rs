@@ -9,4 +9,27 @@ fn tests() {
     //t.pass("tests/06-bound-trouble.rs");
     //t.pass("tests/07-associated-type.rs");
     //t.pass("tests/08-escape-hatch.rs");
+    //t.pass("tests/09-enum.rs");
+    //t.pass("tests/10-tuple-struct.rs");
+    //t.pass("tests/11-redact.rs");
+    //t.pass("tests/12-skip.rs");
+    //t.pass("tests/13-with.rs");
+    //t.pass("tests/14-field-references.rs");
+    //t.compile_fail("tests/15-unknown-field-reference.rs");
+    //t.pass("tests/16-custom-display.rs");
+    //t.compile_fail("tests/17-invalid-format.rs");
+    //t.pass("tests/18-general-bounds.rs");
+    //t.pass("tests/19-field-bound.rs");
+    //t.compile_fail("tests/20-unknown-bound-param.rs");
+    //t.pass("tests/21-truncate.rs");
+    //t.pass("tests/22-byte-formats.rs");
+    //t.pass("tests/23-alternate-mode.rs");
+    //t.pass("tests/24-flatten.rs");
+    //t.pass("tests/25-recursion-limits.rs");
+    //t.pass("tests/26-visitor.rs");
+    //t.pass("tests/27-no-alloc-defmt.rs");
+    //t.pass("tests/28-debug-diff.rs");
+    //t.pass("tests/29-container-defaults.rs");
+    //t.compile_fail("tests/30-unknown-rename-all.rs");
+    //t.pass("tests/31-union.rs");
 }